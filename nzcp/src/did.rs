@@ -0,0 +1,196 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use p256::{
+    ecdsa::VerifyingKey, elliptic_curve::generic_array::GenericArray, elliptic_curve::sec1::FromEncodedPoint,
+    EncodedPoint, PublicKey,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::payload::cwt::DecentralizedIdentifier;
+
+#[derive(Debug, Error)]
+pub enum DidError {
+    #[error("Fetching the DID document failed: {0}")]
+    Fetch(String),
+    #[error("The DID document was not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("No `verificationMethod` in the DID document matched the given `kid`")]
+    VerificationMethodNotFound,
+    #[error("The `publicKeyJwk` was not a valid P-256 elliptic curve key")]
+    InvalidJwk,
+}
+
+/// Performs the HTTP fetch of a `did:web` document, injectable so that callers can supply
+/// caching, retries, or an offline fixture in tests.
+pub trait DidWebClient {
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Fetches the raw bytes at `url`.
+    fn get(&self, url: &str) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// Performs the HTTP fetch of a `did:web` document asynchronously, e.g. backed by the browser's
+/// `fetch` from the [`crate::wasm`] bindings.
+pub trait AsyncDidWebClient {
+    type Error: std::error::Error + Send + Sync + 'static;
+    type Future: std::future::Future<Output = Result<Vec<u8>, Self::Error>>;
+
+    /// Fetches the raw bytes at `url`.
+    fn get(&self, url: &str) -> Self::Future;
+}
+
+/// A minimal [did:web](https://w3c-ccg.github.io/did-method-web/) document, containing only the
+/// fields needed to locate a verification method's signing key.
+#[derive(Debug, Deserialize)]
+struct DidDocument {
+    #[serde(rename = "verificationMethod")]
+    verification_method: Vec<VerificationMethod>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerificationMethod {
+    id: String,
+    #[serde(rename = "publicKeyJwk")]
+    public_key_jwk: Jwk,
+}
+
+/// A JSON Web Key ([RFC 7517](https://datatracker.ietf.org/doc/html/rfc7517)), restricted to the
+/// EC/P-256 keys used for NZ COVID Pass issuer signing keys.
+#[derive(Debug, Deserialize)]
+struct Jwk {
+    kty: String,
+    crv: String,
+    x: String,
+    y: String,
+}
+
+impl Jwk {
+    fn to_verifying_key(&self) -> Result<VerifyingKey, DidError> {
+        if self.kty != "EC" || self.crv != "P-256" {
+            return Err(DidError::InvalidJwk);
+        }
+
+        let x = URL_SAFE_NO_PAD.decode(&self.x).map_err(|_| DidError::InvalidJwk)?;
+        let y = URL_SAFE_NO_PAD.decode(&self.y).map_err(|_| DidError::InvalidJwk)?;
+        if x.len() != 32 || y.len() != 32 {
+            return Err(DidError::InvalidJwk);
+        }
+
+        let encoded_point =
+            EncodedPoint::from_affine_coordinates(GenericArray::from_slice(&x), GenericArray::from_slice(&y), false);
+
+        Option::<PublicKey>::from(PublicKey::from_encoded_point(&encoded_point))
+            .map(VerifyingKey::from)
+            .ok_or(DidError::InvalidJwk)
+    }
+}
+
+/// Resolves the verifying key for `kid` from the `did:web` document identified by `identifier`,
+/// using `client` to perform the HTTP fetch.
+///
+/// Builds the well-known URL as `https://<domain>/.well-known/did.json`, then finds the
+/// `verificationMethod` whose fragment (the part of its `id` after `#`) matches `kid`.
+pub fn resolve_verifying_key<C: DidWebClient>(
+    identifier: &DecentralizedIdentifier,
+    kid: &str,
+    client: &C,
+) -> Result<VerifyingKey, DidError> {
+    let body = client.get(&did_document_url(identifier)).map_err(|err| DidError::Fetch(err.to_string()))?;
+    verifying_key_from_document(&body, kid)
+}
+
+/// The asynchronous counterpart to [`resolve_verifying_key`], for clients (such as a browser
+/// `fetch`) that can only perform the HTTP fetch asynchronously.
+pub async fn resolve_verifying_key_async<C: AsyncDidWebClient>(
+    identifier: &DecentralizedIdentifier<'_>,
+    kid: &str,
+    client: &C,
+) -> Result<VerifyingKey, DidError> {
+    let body = client.get(&did_document_url(identifier)).await.map_err(|err| DidError::Fetch(err.to_string()))?;
+    verifying_key_from_document(&body, kid)
+}
+
+fn did_document_url(identifier: &DecentralizedIdentifier) -> String {
+    let DecentralizedIdentifier::Web(domain) = identifier;
+    format!("https://{domain}/.well-known/did.json")
+}
+
+fn verifying_key_from_document(document: &[u8], kid: &str) -> Result<VerifyingKey, DidError> {
+    let document: DidDocument = serde_json::from_slice(document)?;
+
+    document
+        .verification_method
+        .iter()
+        .find(|method| method.id.rsplit('#').next() == Some(kid))
+        .ok_or(DidError::VerificationMethodNotFound)?
+        .public_key_jwk
+        .to_verifying_key()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+    use super::*;
+
+    // `publicKeyJwk` coordinates of the P-256 generator point, used as a fixture key (not a real
+    // NZ COVID Pass issuer key).
+    const DID_DOCUMENT: &str = r#"{
+        "verificationMethod": [
+            {
+                "id": "did:web:example.nz#key-1",
+                "publicKeyJwk": {
+                    "kty": "EC",
+                    "crv": "P-256",
+                    "x": "axfR8uEsQkf4vOblY6RA8ncDfYEt6zOg9KE5RdiYwpY",
+                    "y": "T-NC4v4af5uO5-tKfA-eFivOM1drMV7Oy7ZAaDe_UfU"
+                }
+            }
+        ]
+    }"#;
+
+    struct FixtureClient(&'static str);
+
+    impl DidWebClient for FixtureClient {
+        type Error = Infallible;
+
+        fn get(&self, _url: &str) -> Result<Vec<u8>, Self::Error> {
+            Ok(self.0.as_bytes().to_vec())
+        }
+    }
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn resolves_the_verification_method_matching_kid() {
+        let identifier = DecentralizedIdentifier::Web("example.nz");
+        let client = FixtureClient(DID_DOCUMENT);
+
+        let verifying_key = resolve_verifying_key(&identifier, "key-1", &client).unwrap();
+        let encoded_point = verifying_key.to_encoded_point(false);
+
+        assert_eq!(
+            &encoded_point.as_bytes()[1..33],
+            decode_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296").as_slice()
+        );
+        assert_eq!(
+            &encoded_point.as_bytes()[33..65],
+            decode_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5").as_slice()
+        );
+    }
+
+    #[test]
+    fn errors_when_no_verification_method_matches_kid() {
+        let identifier = DecentralizedIdentifier::Web("example.nz");
+        let client = FixtureClient(DID_DOCUMENT);
+
+        assert!(matches!(
+            resolve_verifying_key(&identifier, "key-2", &client),
+            Err(DidError::VerificationMethodNotFound)
+        ));
+    }
+}
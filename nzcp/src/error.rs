@@ -1,9 +1,29 @@
+use chrono::NaiveDateTime;
 use thiserror::Error;
 
+use crate::did::DidError;
+use crate::pass::registry::PassRegistryError;
 use crate::payload::barcode::QrBarcodeError;
+use crate::payload::cose::CoseError;
 
 #[derive(Debug, Error)]
 pub enum NzcpError {
     #[error("Invalid QR barcode: {0:?}")]
-    QrBarcode(QrBarcodeError),
+    QrBarcode(#[from] QrBarcodeError),
+    #[error("The COSE_Sign1 signature did not verify: {0}")]
+    SignatureInvalid(#[from] CoseError),
+    #[error("Failed to resolve the issuer's signing key: {0}")]
+    DidResolution(#[from] DidError),
+    #[error("Failed to CBOR decode the CWT payload: {0}")]
+    Cbor(#[from] serde_cbor::Error),
+    #[error("The issuer {0:?} is not in the set of trusted issuers")]
+    UntrustedIssuer(String),
+    #[error("Expected a {expected:?} pass, but `vc.type[1]` was {found:?}")]
+    CredentialTypeMismatch { expected: &'static str, found: String },
+    #[error(transparent)]
+    PassRegistry(#[from] PassRegistryError),
+    #[error("The pass expired at {0}")]
+    Expired(NaiveDateTime),
+    #[error("The pass is not valid until {0}")]
+    NotYetValid(NaiveDateTime),
 }
@@ -0,0 +1,9 @@
+pub mod did;
+pub mod error;
+pub mod pass;
+pub mod payload;
+pub mod verify;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+
+pub use verify::{verify, verify_dynamic, VerifierConfig};
@@ -1,4 +1,5 @@
 pub mod public_covid_pass;
+pub mod registry;
 
 pub trait Pass {
     /// The type ID of the pass, given in `vc.type[1]`. (e.g. 'PublicCovidPass')
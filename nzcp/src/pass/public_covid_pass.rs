@@ -1,5 +1,5 @@
 use chrono::NaiveDate;
-use serde::{de::Error, Deserialize, Deserializer};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 use super::Pass;
 
@@ -11,21 +11,26 @@ pub enum PublicCovidPassError {
     InvalidDateOfBirth,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq)]
-struct PublicCovidPass<'a> {
+// `date_of_birth` goes through the ISO 8601 helpers below, not chrono's own `NaiveDate` serde impls.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PublicCovidPass {
     /// Given name(s) of the subject of the pass.
     #[serde(rename = "givenName")]
-    given_name: &'a str,
+    given_name: String,
 
     /// Family name(s) of the subject of the pass.
     #[serde(rename = "familyName")]
-    family_name: &'a str,
+    family_name: String,
 
-    #[serde(rename = "dob", deserialize_with = "deserialize_iso_8601_date")]
+    #[serde(
+        rename = "dob",
+        deserialize_with = "deserialize_iso_8601_date",
+        serialize_with = "serialize_iso_8601_date"
+    )]
     date_of_birth: NaiveDate,
 }
 
-impl<'a> Pass for PublicCovidPass<'a> {
+impl Pass for PublicCovidPass {
     const CREDENTIAL_TYPE: &'static str = "PublicCovidPass";
 }
 
@@ -38,6 +43,13 @@ where
         .map_err(|_| D::Error::custom(PublicCovidPassError::InvalidDateOfBirth))
 }
 
+fn serialize_iso_8601_date<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.format("%Y-%m-%d").to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,10 +66,22 @@ mod tests {
         assert_eq!(
             payload,
             PublicCovidPass {
-                given_name: "John Andrew",
-                family_name: "Doe",
+                given_name: "John Andrew".to_string(),
+                family_name: "Doe".to_string(),
                 date_of_birth: NaiveDate::from_ymd(1979, 04, 14),
             }
         )
     }
+
+    #[test]
+    fn serialize_json() {
+        let payload = PublicCovidPass {
+            given_name: "John Andrew".to_string(),
+            family_name: "Doe".to_string(),
+            date_of_birth: NaiveDate::from_ymd(1979, 04, 14),
+        };
+
+        let value: serde_json::Value = serde_json::to_value(&payload).unwrap();
+        assert_eq!(value["dob"], "1979-04-14");
+    }
 }
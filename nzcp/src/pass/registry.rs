@@ -0,0 +1,45 @@
+use serde::Serialize;
+use serde_cbor::Value;
+use thiserror::Error;
+
+use super::public_covid_pass::PublicCovidPass;
+use super::Pass;
+
+#[derive(Debug, Error)]
+pub enum PassRegistryError {
+    #[error("No registered pass type matches the credential type {0:?}")]
+    UnknownCredentialType(String),
+    #[error("Failed to deserialize the {credential_type:?} credential subject: {source}")]
+    Deserialize {
+        credential_type: &'static str,
+        source: serde_cbor::Error,
+    },
+}
+
+/// The `credentialSubject` of a verified pass, dispatched to its concrete [`Pass`] type based on
+/// `vc.type[1]`.
+///
+/// Adding support for a future NZ pass schema only requires a new `Pass` implementor, a variant
+/// here and a match arm in [`VerifiedPass::from_credential_subject`] — the COSE/DID verification core in
+/// [`crate::verify`] does not need to change.
+#[derive(Debug, Serialize, PartialEq)]
+#[non_exhaustive]
+pub enum VerifiedPass {
+    PublicCovidPass(PublicCovidPass),
+}
+
+impl VerifiedPass {
+    /// Deserializes `credential_subject` into the concrete pass type registered for
+    /// `credential_type` (i.e. `vc.type[1]`).
+    pub fn from_credential_subject(credential_type: &str, credential_subject: Value) -> Result<Self, PassRegistryError> {
+        match credential_type {
+            PublicCovidPass::CREDENTIAL_TYPE => serde_cbor::value::from_value(credential_subject)
+                .map(VerifiedPass::PublicCovidPass)
+                .map_err(|source| PassRegistryError::Deserialize {
+                    credential_type: PublicCovidPass::CREDENTIAL_TYPE,
+                    source,
+                }),
+            other => Err(PassRegistryError::UnknownCredentialType(other.to_string())),
+        }
+    }
+}
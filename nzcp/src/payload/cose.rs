@@ -0,0 +1,201 @@
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde_cbor::Value;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CoseError {
+    #[error("The COSE_Sign1 structure MUST be encoded as a tagged, 4-element CBOR array")]
+    InvalidStructure,
+    #[error("The protected header of the COSE_Sign1 structure MUST be a CBOR-encoded map")]
+    InvalidProtectedHeader,
+    #[error("The protected header MUST contain an `alg` (1) label")]
+    MissingAlgorithm,
+    #[error("Unsupported COSE algorithm identifier: {0}")]
+    UnsupportedAlgorithm(i128),
+    #[error("The signature MUST be a 64-byte `r||s` encoded ECDSA signature")]
+    InvalidSignature,
+    #[error("The signature did not verify against the given public key")]
+    SignatureVerificationFailed,
+    #[error("The protected header did not contain a `kid` (4) label usable to look up a signing key")]
+    MissingKeyId,
+    #[error("Failed to CBOR (de)serialize the COSE_Sign1 structure: {0}")]
+    Cbor(#[from] serde_cbor::Error),
+}
+
+/// The `alg` (label `1`) value of a COSE protected header, as registered in the
+/// [IANA COSE Algorithms registry](https://www.iana.org/assignments/cose/cose.xhtml#algorithms).
+///
+/// Modelled as a typed enum (rather than a raw integer) so that an unsupported or unrecognised
+/// algorithm is rejected up front instead of being silently accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum COSEAlgorithm {
+    /// ECDSA using the NIST P-256 curve and SHA-256, registered as `-7`.
+    ES256,
+}
+
+impl COSEAlgorithm {
+    fn from_value(value: i128) -> Result<Self, CoseError> {
+        match value {
+            -7 => Ok(COSEAlgorithm::ES256),
+            other => Err(CoseError::UnsupportedAlgorithm(other)),
+        }
+    }
+}
+
+/// A decoded, but not yet verified, `COSE_Sign1` structure as described in
+/// [RFC 8152 §4.2](https://datatracker.ietf.org/doc/html/rfc8152#section-4.2).
+///
+/// The New Zealand COVID Pass payload is the CBOR bytes of a tagged (tag 18) `COSE_Sign1` array
+/// of `[protected: bstr, unprotected: map, payload: bstr, signature: bstr]`, not a bare CWT map,
+/// so this MUST be parsed and verified before the `payload` bstr is handed to [`super::cwt::CwtPayload`].
+pub struct CoseSign1 {
+    protected: Vec<u8>,
+    payload: Vec<u8>,
+    signature: Vec<u8>,
+    algorithm: COSEAlgorithm,
+    key_id: Option<Vec<u8>>,
+}
+
+impl CoseSign1 {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CoseError> {
+        use CoseError::*;
+
+        // `COSE_Sign1` is CBOR tag 18, encoded as the single byte `0xd2` (major type 6, tag number
+        // 18) immediately preceding the 4-element array. Check this on the raw bytes rather than
+        // matching `Value::Tag` after decoding: by default `serde_cbor` (without its non-default
+        // `tags` feature) silently strips tags while decoding to `Value`, so a tagged and an
+        // untagged array would otherwise be indistinguishable once inside `Value`.
+        let body = bytes.strip_prefix(&[0xd2]).ok_or(InvalidStructure)?;
+
+        let value: Value = serde_cbor::from_slice(body)?;
+        let mut array = match value {
+            Value::Array(array) if array.len() == 4 => array.into_iter(),
+            _ => return Err(InvalidStructure),
+        };
+
+        let protected = match array.next() {
+            Some(Value::Bytes(bytes)) => bytes,
+            _ => return Err(InvalidStructure),
+        };
+        let _unprotected = array.next().ok_or(InvalidStructure)?;
+        let payload = match array.next() {
+            Some(Value::Bytes(bytes)) => bytes,
+            _ => return Err(InvalidStructure),
+        };
+        let signature = match array.next() {
+            Some(Value::Bytes(bytes)) => bytes,
+            _ => return Err(InvalidStructure),
+        };
+
+        let header = match serde_cbor::from_slice(&protected)? {
+            Value::Map(header) => header,
+            _ => return Err(InvalidProtectedHeader),
+        };
+
+        let algorithm = match header.get(&Value::Integer(1)) {
+            Some(Value::Integer(alg)) => COSEAlgorithm::from_value(*alg)?,
+            _ => return Err(MissingAlgorithm),
+        };
+        let key_id = match header.get(&Value::Integer(4)) {
+            Some(Value::Bytes(kid)) => Some(kid.clone()),
+            _ => None,
+        };
+
+        Ok(CoseSign1 { protected, payload, signature, algorithm, key_id })
+    }
+
+    /// The `kid` (label `4`) of the protected header, used to look up the issuer's signing key.
+    pub fn key_id(&self) -> Option<&[u8]> {
+        self.key_id.as_deref()
+    }
+
+    /// The inner CWT payload bytes, *before* signature verification.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Verifies the COSE signature against `verifying_key`, returning the inner CWT payload bytes
+    /// on success.
+    ///
+    /// Reconstructs the `Sig_structure` as `["Signature1", protected, h'', payload]` per
+    /// [RFC 8152 §4.4](https://datatracker.ietf.org/doc/html/rfc8152#section-4.4) and verifies the
+    /// 64-byte `r||s` signature as ECDSA over NIST P-256 with a SHA-256 digest.
+    pub fn verify(&self, verifying_key: &VerifyingKey) -> Result<&[u8], CoseError> {
+        let COSEAlgorithm::ES256 = self.algorithm;
+
+        let signature = Signature::try_from(self.signature.as_slice()).map_err(|_| CoseError::InvalidSignature)?;
+
+        let sig_structure = Value::Array(vec![
+            Value::Text("Signature1".to_string()),
+            Value::Bytes(self.protected.clone()),
+            Value::Bytes(Vec::new()),
+            Value::Bytes(self.payload.clone()),
+        ]);
+        let to_be_signed = serde_cbor::to_vec(&sig_structure)?;
+
+        verifying_key
+            .verify(&to_be_signed, &signature)
+            .map_err(|_| CoseError::SignatureVerificationFailed)?;
+
+        Ok(&self.payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::{
+        elliptic_curve::generic_array::GenericArray, elliptic_curve::sec1::FromEncodedPoint, EncodedPoint, PublicKey,
+    };
+
+    use super::*;
+
+    // Protected header `{1: -7, 4: h'6b65792d31'}` (`alg: ES256, kid: "key-1"`), payload
+    // `"hello world"`, tagged and signed over the P-256 generator point's own key pair as the
+    // issuer's "public" key — a fixture vector, not a real NZ COVID Pass.
+    const COSE_SIGN1: &str = "d2844aa2012604456b65792d31a04c6b68656c6c6f20776f726c6458403f5fee2c032589b165e054e0565089cb91ef4e9dcac784c759f6a48ee2a0b48a85a70fa7f3e00286e95023b4303b67764eac7bd986150c2e6d4ce255b31a97b7";
+    const COSE_SIGN1_FLIPPED_SIGNATURE: &str = "d2844aa2012604456b65792d31a04c6b68656c6c6f20776f726c645840c05fee2c032589b165e054e0565089cb91ef4e9dcac784c759f6a48ee2a0b48a85a70fa7f3e00286e95023b4303b67764eac7bd986150c2e6d4ce255b31a97b7";
+    const UNTAGGED_COSE_SIGN1: &str = "844aa2012604456b65792d31a04c6b68656c6c6f20776f726c6458403f5fee2c032589b165e054e0565089cb91ef4e9dcac784c759f6a48ee2a0b48a85a70fa7f3e00286e95023b4303b67764eac7bd986150c2e6d4ce255b31a97b7";
+
+    const PUBLIC_KEY_X: &str = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+    const PUBLIC_KEY_Y: &str = "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn test_verifying_key() -> VerifyingKey {
+        let x = decode_hex(PUBLIC_KEY_X);
+        let y = decode_hex(PUBLIC_KEY_Y);
+        let encoded_point =
+            EncodedPoint::from_affine_coordinates(GenericArray::from_slice(&x), GenericArray::from_slice(&y), false);
+        VerifyingKey::from(PublicKey::from_encoded_point(&encoded_point).unwrap())
+    }
+
+    #[test]
+    fn verifies_a_valid_signature() {
+        let bytes = decode_hex(COSE_SIGN1);
+        let cose_sign1 = CoseSign1::from_bytes(&bytes).unwrap();
+
+        assert_eq!(cose_sign1.key_id(), Some(b"key-1".as_slice()));
+
+        // The CBOR-encoded text string `"hello world"` (a `0x6b`-prefixed major-type-3 tstr), not
+        // the bare ASCII bytes.
+        let expected_payload = decode_hex("6b68656c6c6f20776f726c64");
+        assert_eq!(cose_sign1.verify(&test_verifying_key()).unwrap(), expected_payload.as_slice());
+    }
+
+    #[test]
+    fn rejects_a_flipped_signature_byte() {
+        let bytes = decode_hex(COSE_SIGN1_FLIPPED_SIGNATURE);
+        let cose_sign1 = CoseSign1::from_bytes(&bytes).unwrap();
+
+        assert!(matches!(cose_sign1.verify(&test_verifying_key()), Err(CoseError::SignatureVerificationFailed)));
+    }
+
+    #[test]
+    fn rejects_an_untagged_array() {
+        let bytes = decode_hex(UNTAGGED_COSE_SIGN1);
+
+        assert!(matches!(CoseSign1::from_bytes(&bytes), Err(CoseError::InvalidStructure)));
+    }
+}
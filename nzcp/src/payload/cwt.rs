@@ -1,13 +1,32 @@
 use std::fmt;
 
 use chrono::NaiveDateTime;
+use p256::ecdsa::VerifyingKey;
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer,
 };
+use thiserror::Error;
 use uuid::Uuid;
 
-use super::barcode::QrBarcode;
+use super::cose::{CoseError, CoseSign1};
+
+#[derive(Debug, Error)]
+pub enum CwtPayloadError {
+    #[error(transparent)]
+    Cose(#[from] CoseError),
+    #[error("Failed to CBOR deserialize the CWT payload: {0}")]
+    Cbor(#[from] serde_cbor::Error),
+}
+
+impl From<CwtPayloadError> for crate::error::NzcpError {
+    fn from(error: CwtPayloadError) -> Self {
+        match error {
+            CwtPayloadError::Cose(error) => crate::error::NzcpError::SignatureInvalid(error),
+            CwtPayloadError::Cbor(error) => crate::error::NzcpError::Cbor(error),
+        }
+    }
+}
 
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct CwtPayload<'a, T> {
@@ -28,12 +47,56 @@ pub struct CwtPayload<'a, T> {
 }
 
 impl<'a, T> CwtPayload<'a, T> {
-    pub fn from_barcode(barcode: &'a QrBarcode) -> Result<Self, ()>
+    /// Verifies `cose_sign1` against `verifying_key` and deserializes the resulting CWT payload.
+    pub fn from_barcode(cose_sign1: &'a CoseSign1, verifying_key: &VerifyingKey) -> Result<Self, CwtPayloadError>
+    where
+        T: Deserialize<'a>,
+    {
+        Self::from_payload_bytes(cose_sign1.verify(verifying_key)?)
+    }
+
+    /// Deserializes the CWT payload of `cose_sign1` *without* verifying its signature.
+    ///
+    /// Only the issuer, needed to resolve the signing key, may be relied upon from the result;
+    /// every other claim MUST NOT be relied upon until [`CwtPayload::from_barcode`] succeeds.
+    pub fn from_cose_unverified(cose_sign1: &'a CoseSign1) -> Result<Self, CwtPayloadError>
     where
         T: Deserialize<'a>,
     {
-        let mut deserializer = serde_cbor::Deserializer::from_slice(&barcode.0);
-        Ok(CwtPayload::deserialize(&mut deserializer).unwrap())
+        Self::from_payload_bytes(cose_sign1.payload())
+    }
+
+    fn from_payload_bytes(bytes: &'a [u8]) -> Result<Self, CwtPayloadError>
+    where
+        T: Deserialize<'a>,
+    {
+        let mut deserializer = serde_cbor::Deserializer::from_slice(bytes);
+        Ok(CwtPayload::deserialize(&mut deserializer)?)
+    }
+
+    /// The `iss` (issuer) Decentralized Identifier, used to resolve the key that signed this pass.
+    pub fn issuer(&self) -> &DecentralizedIdentifier<'a> {
+        &self.issuer
+    }
+
+    /// The `nbf` (not before) claim: the pass is not valid before this instant.
+    pub fn not_before(&self) -> NaiveDateTime {
+        self.not_before
+    }
+
+    /// The `exp` (expiry) claim: the pass is not valid from this instant onwards.
+    pub fn expiry(&self) -> NaiveDateTime {
+        self.expiry
+    }
+
+    /// The second element of the `vc.type` array (e.g. `"PublicCovidPass"`).
+    pub fn credential_type(&self) -> &'a str {
+        self.verifiable_credential._type.1
+    }
+
+    /// Consumes the payload, returning the `vc.credentialSubject`.
+    pub fn into_credential_subject(self) -> T {
+        self.verifiable_credential.credential_subject
     }
 }
 
@@ -74,10 +137,18 @@ where
 }
 
 #[derive(Debug, PartialEq, Eq)]
-enum DecentralizedIdentifier<'a> {
+pub enum DecentralizedIdentifier<'a> {
     Web(&'a str),
 }
 
+impl<'a> fmt::Display for DecentralizedIdentifier<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecentralizedIdentifier::Web(domain) => write!(formatter, "did:web:{domain}"),
+        }
+    }
+}
+
 struct DecentralizedIdentifierVisitor;
 
 impl<'de> Visitor<'de> for DecentralizedIdentifierVisitor {
@@ -0,0 +1,3 @@
+pub mod barcode;
+pub mod cose;
+pub mod cwt;
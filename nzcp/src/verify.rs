@@ -0,0 +1,200 @@
+use chrono::{NaiveDateTime, Utc};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde_cbor::Value;
+
+#[cfg(target_arch = "wasm32")]
+use crate::did::{resolve_verifying_key_async, AsyncDidWebClient};
+use crate::did::{resolve_verifying_key, DidWebClient};
+use crate::error::NzcpError;
+use crate::pass::registry::VerifiedPass;
+use crate::pass::Pass;
+use crate::payload::barcode::QrBarcode;
+use crate::payload::cose::{CoseError, CoseSign1};
+use crate::payload::cwt::{CwtPayload, DecentralizedIdentifier};
+
+/// Configuration for [`verify`]/[`verify_dynamic`]: the set of trusted issuer DIDs and the clock
+/// used to check temporal validity.
+pub struct VerifierConfig<'a, Clock = fn() -> NaiveDateTime> {
+    /// `did:web` issuers (e.g. `"did:web:nzcp.covid19.health.nz"`) that are trusted to sign passes.
+    pub trusted_issuers: &'a [&'a str],
+    /// Returns the instant used to check `nbf`/`exp`. Defaults to [`Utc::now`].
+    pub now: Clock,
+}
+
+impl<'a> VerifierConfig<'a> {
+    /// A config that trusts `trusted_issuers` and uses the real wall-clock time.
+    pub fn new(trusted_issuers: &'a [&'a str]) -> Self {
+        VerifierConfig { trusted_issuers, now: || Utc::now().naive_utc() }
+    }
+}
+
+impl<'a, Clock> VerifierConfig<'a, Clock>
+where
+    Clock: Fn() -> NaiveDateTime,
+{
+    /// A config that trusts `trusted_issuers`, using `now` as an injectable clock (e.g. for tests).
+    pub fn with_clock(trusted_issuers: &'a [&'a str], now: Clock) -> Self {
+        VerifierConfig { trusted_issuers, now }
+    }
+}
+
+/// Turns a scanned `NZCP:/1/...` string into a trusted, temporally valid `T`.
+///
+/// Chains barcode decode → `did:web` resolution → COSE_Sign1 verification → payload
+/// deserialization, then enforces that the issuer is in `config.trusted_issuers`, that the pass is
+/// currently within its `nbf`/`exp` window, and that `vc.type[1]` matches `T::CREDENTIAL_TYPE`.
+pub fn verify<T, Clock, Did>(barcode: &str, config: &VerifierConfig<Clock>, did_client: &Did) -> Result<T, NzcpError>
+where
+    T: Pass + DeserializeOwned,
+    Clock: Fn() -> NaiveDateTime,
+    Did: DidWebClient,
+{
+    let (cose_sign1, verifying_key) = decode_and_resolve_key(barcode, config, did_client)?;
+    let payload: CwtPayload<T> = CwtPayload::from_barcode(&cose_sign1, &verifying_key)?;
+
+    if payload.credential_type() != T::CREDENTIAL_TYPE {
+        return Err(NzcpError::CredentialTypeMismatch {
+            expected: T::CREDENTIAL_TYPE,
+            found: payload.credential_type().to_string(),
+        });
+    }
+    check_temporal_validity(config, payload.not_before(), payload.expiry())?;
+
+    Ok(payload.into_credential_subject())
+}
+
+/// Like [`verify`], but instead of requiring the caller to know the pass type ahead of time,
+/// dispatches on `vc.type[1]` to whichever [`Pass`](crate::pass::Pass) implementor is registered
+/// for it in [`VerifiedPass`].
+pub fn verify_dynamic<Clock, Did>(
+    barcode: &str,
+    config: &VerifierConfig<Clock>,
+    did_client: &Did,
+) -> Result<VerifiedPass, NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+    Did: DidWebClient,
+{
+    let (cose_sign1, verifying_key) = decode_and_resolve_key(barcode, config, did_client)?;
+    dispatch_verified(&cose_sign1, &verifying_key, config)
+}
+
+/// The async counterpart of [`verify_dynamic`], used when the DID document can only be fetched
+/// asynchronously (e.g. through [`crate::wasm`]'s browser `fetch` callback).
+///
+/// Shares every step with [`verify_dynamic`] except the key resolution itself, which is the only
+/// part that differs between a synchronous and an asynchronous [`DidWebClient`].
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn verify_dynamic_async<Clock, Did>(
+    barcode: &str,
+    config: &VerifierConfig<'_, Clock>,
+    did_client: &Did,
+) -> Result<VerifiedPass, NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+    Did: AsyncDidWebClient,
+{
+    let (cose_sign1, verifying_key) = decode_and_resolve_key_async(barcode, config, did_client).await?;
+    dispatch_verified(&cose_sign1, &verifying_key, config)
+}
+
+/// Parses `barcode`, and reads the `kid` (from its protected header) and the issuer (from its CWT
+/// payload) needed to resolve a signing key *before* the signature has been checked — that is the
+/// only way to know which key to fetch. Every other claim is only trusted once the signature has
+/// been verified against the resolved key.
+///
+/// Returns the decoded envelope, the issuer's domain, and the `kid` (rather than a borrowed
+/// [`DecentralizedIdentifier`]) so that callers can resolve the key either synchronously or
+/// asynchronously without fighting the borrow checker over the intermediate unverified payload.
+fn decode_unverified<Clock>(barcode: &str, config: &VerifierConfig<Clock>) -> Result<(CoseSign1, String, String), NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+{
+    let barcode: QrBarcode = barcode.parse()?;
+    let cose_sign1 = CoseSign1::from_bytes(&barcode.0)?;
+
+    let kid = cose_sign1
+        .key_id()
+        .and_then(|kid| std::str::from_utf8(kid).ok())
+        .ok_or(CoseError::MissingKeyId)?
+        .to_string();
+
+    let domain = {
+        let unverified_payload = CwtPayload::<'_, IgnoredAny>::from_cose_unverified(&cose_sign1)?;
+        let DecentralizedIdentifier::Web(domain) = unverified_payload.issuer();
+        let issuer = unverified_payload.issuer().to_string();
+        if !config.trusted_issuers.contains(&issuer.as_str()) {
+            return Err(NzcpError::UntrustedIssuer(issuer));
+        }
+        domain.to_string()
+    };
+
+    Ok((cose_sign1, domain, kid))
+}
+
+/// Decodes `barcode` and resolves the verifying key for its (as yet unverified) issuer.
+fn decode_and_resolve_key<Clock, Did>(
+    barcode: &str,
+    config: &VerifierConfig<Clock>,
+    did_client: &Did,
+) -> Result<(CoseSign1, p256::ecdsa::VerifyingKey), NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+    Did: DidWebClient,
+{
+    let (cose_sign1, domain, kid) = decode_unverified(barcode, config)?;
+    let verifying_key = resolve_verifying_key(&DecentralizedIdentifier::Web(&domain), &kid, did_client)?;
+    Ok((cose_sign1, verifying_key))
+}
+
+/// The async counterpart of [`decode_and_resolve_key`].
+#[cfg(target_arch = "wasm32")]
+async fn decode_and_resolve_key_async<Clock, Did>(
+    barcode: &str,
+    config: &VerifierConfig<'_, Clock>,
+    did_client: &Did,
+) -> Result<(CoseSign1, p256::ecdsa::VerifyingKey), NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+    Did: AsyncDidWebClient,
+{
+    let (cose_sign1, domain, kid) = decode_unverified(barcode, config)?;
+    let verifying_key = resolve_verifying_key_async(&DecentralizedIdentifier::Web(&domain), &kid, did_client).await?;
+    Ok((cose_sign1, verifying_key))
+}
+
+/// Verifies the CWT payload against `verifying_key`, checks its temporal validity, and dispatches
+/// `vc.credentialSubject` to the matching [`Pass`](crate::pass::Pass) implementor.
+fn dispatch_verified<Clock>(
+    cose_sign1: &CoseSign1,
+    verifying_key: &p256::ecdsa::VerifyingKey,
+    config: &VerifierConfig<Clock>,
+) -> Result<VerifiedPass, NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+{
+    let payload: CwtPayload<Value> = CwtPayload::from_barcode(cose_sign1, verifying_key)?;
+
+    let credential_type = payload.credential_type().to_string();
+    check_temporal_validity(config, payload.not_before(), payload.expiry())?;
+
+    Ok(VerifiedPass::from_credential_subject(&credential_type, payload.into_credential_subject())?)
+}
+
+fn check_temporal_validity<Clock>(
+    config: &VerifierConfig<Clock>,
+    not_before: NaiveDateTime,
+    expiry: NaiveDateTime,
+) -> Result<(), NzcpError>
+where
+    Clock: Fn() -> NaiveDateTime,
+{
+    let now = (config.now)();
+    if now < not_before {
+        return Err(NzcpError::NotYetValid(not_before));
+    }
+    if now >= expiry {
+        return Err(NzcpError::Expired(expiry));
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! `wasm-bindgen` bindings exposing [`crate::verify_dynamic`] to JavaScript, so that this crate
+//! can replace the original [nzcp-js](https://github.com/haxxnz/nzcp-js) browser verifier.
+//!
+//! DID resolution is performed through a caller-provided `fetch`-style callback rather than a
+//! bundled HTTP client, so that the browser's own networking (and any caching or TLS trust store
+//! it already has configured) is reused.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use js_sys::{Function, Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::did::AsyncDidWebClient;
+use crate::error::NzcpError;
+use crate::verify::verify_dynamic_async;
+use crate::VerifierConfig;
+
+/// Adapts a JS `(url: string) => Promise<Uint8Array>` callback to [`AsyncDidWebClient`].
+struct JsFetchClient {
+    fetch: Function,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("the `fetch` callback failed: {0}")]
+struct JsFetchError(String);
+
+impl AsyncDidWebClient for JsFetchClient {
+    type Error = JsFetchError;
+    type Future = Pin<Box<dyn Future<Output = Result<Vec<u8>, Self::Error>>>>;
+
+    fn get(&self, url: &str) -> Self::Future {
+        let result = self.fetch.call1(&JsValue::NULL, &JsValue::from_str(url));
+        Box::pin(async move {
+            let promise: Promise = result.map_err(|err| JsFetchError(format!("{err:?}")))?.into();
+            let value = JsFuture::from(promise).await.map_err(|err| JsFetchError(format!("{err:?}")))?;
+            Ok(Uint8Array::new(&value).to_vec())
+        })
+    }
+}
+
+fn to_js_error(error: NzcpError) -> JsValue {
+    js_sys::Error::new(&error.to_string()).into()
+}
+
+/// Verifies a scanned `NZCP:/1/...` barcode against `trusted_issuers`, resolving the issuer's
+/// signing key through `fetch`.
+///
+/// `fetch` is called as `fetch(url)` and MUST return a `Promise<Uint8Array>` of the response body,
+/// e.g. `(url) => fetch(url).then((response) => response.arrayBuffer()).then((buffer) => new
+/// Uint8Array(buffer))`.
+///
+/// Resolves to the verified pass's `credentialSubject` as a plain JS object, or rejects with an
+/// `Error` describing which verification step failed.
+#[wasm_bindgen]
+pub async fn verify(barcode: String, trusted_issuers: Vec<String>, fetch: Function) -> Result<JsValue, JsValue> {
+    let trusted_issuers: Vec<&str> = trusted_issuers.iter().map(String::as_str).collect();
+    let config = VerifierConfig::new(&trusted_issuers);
+    let client = JsFetchClient { fetch };
+
+    let verified_pass = verify_dynamic_async(&barcode, &config, &client).await.map_err(to_js_error)?;
+
+    serde_wasm_bindgen::to_value(&verified_pass).map_err(|err| js_sys::Error::new(&err.to_string()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn verify_rejects_a_malformed_barcode() {
+        let fetch = Function::new_no_args("throw new Error('fetch should not be called')");
+
+        let result = verify("not a valid barcode".to_string(), vec!["did:web:example.nz".to_string()], fetch).await;
+
+        assert!(result.is_err());
+    }
+
+    // `NZCP:/1/...` encoding of a `PublicCovidPass` issued by `did:web:example.nz`, signed over the
+    // P-256 generator point's own key pair — a fixture vector, not a real NZ COVID Pass.
+    const BARCODE: &str = "NZCP:/1/2KCEVIQBEYCEK23FPEWTDICZAENKKY3DORUVBTCZTUCA2UKPP2HPLV5V7BDBYX3DNFZXG4TENFSDU53FMI5GK6DBNVYGYZJONZ5GG3TCMYNF6XQQABRWK6DQDL2IMVYAMJ3GHJDIIBRW63TUMV4HJATYEZUHI5DQOM5C6L3XO53S45ZTFZXXEZZPGIYDCOBPMNZGKZDFNZ2GSYLMOMXXMMLYFJUHI5DQOM5C6L3OPJRXALTDN53GSZBRHEXGQZLBNR2GQLTOPIXWG33OORSXQ5DTF53DCZ3WMVZHG2LPNZSTCLRQFYYGI5DZOBSYE5CWMVZGSZTJMFRGYZKDOJSWIZLOORUWC3DPKB2WE3DJMNBW65TJMRIGC43TOFRXEZLEMVXHI2LBNRJXKYTKMVRXJI3JM5UXMZLOJZQW2ZLEJJQW4ZLKMZQW22LMPFHGC3LFMNCG6ZLDMRXWE2RRHE4DALJQGEWTAMKYIDRJJXIF66L7W46ZFOGW4BOJ675VVMEZWG4J2UT7SZQ35J22QUEITSGMPWDSBMVHHDIUO3XURS5CPXZFP6RLPAWQRX3YLJCIFK54WOJF";
+
+    // `did:web:example.nz`'s document, containing the `publicKeyJwk` (the P-256 generator point)
+    // that verifies [`BARCODE`]'s signature.
+    const DID_DOCUMENT: &str = r#"{
+        "verificationMethod": [
+            {
+                "id": "did:web:example.nz#key-1",
+                "publicKeyJwk": {
+                    "kty": "EC",
+                    "crv": "P-256",
+                    "x": "axfR8uEsQkf4vOblY6RA8ncDfYEt6zOg9KE5RdiYwpY",
+                    "y": "T-NC4v4af5uO5-tKfA-eFivOM1drMV7Oy7ZAaDe_UfU"
+                }
+            }
+        ]
+    }"#;
+
+    #[wasm_bindgen_test]
+    async fn verify_resolves_the_subject_of_a_valid_pass() {
+        let fetch_body = format!("return Promise.resolve(new TextEncoder().encode(`{DID_DOCUMENT}`))");
+        let fetch = Function::new_no_args(&fetch_body);
+
+        let result = verify(BARCODE.to_string(), vec!["did:web:example.nz".to_string()], fetch)
+            .await
+            .expect("a trusted, validly-signed pass should verify");
+
+        let public_covid_pass = js_sys::Reflect::get(&result, &JsValue::from_str("PublicCovidPass")).unwrap();
+        let given_name = js_sys::Reflect::get(&public_covid_pass, &JsValue::from_str("givenName"))
+            .unwrap()
+            .as_string()
+            .unwrap();
+        assert_eq!(given_name, "Jane");
+    }
+}